@@ -1,15 +1,22 @@
 //! Blocking version of Yandex Translate client.
 
-use crate::{AuthMethod, Error, Result, TranslateRequest, TranslateResponse, API_BASE_URL};
+use crate::{
+    cache::{CacheKey, TranslationCache},
+    iam::{self, IamTokenCache, ServiceAccountKey},
+    AuthMethod, DetectRequest, DetectResponse, Error, ListLanguagesRequest, ListLanguagesResponse,
+    Result, TranslateRequest, TranslateResponse, Translation, API_BASE_URL,
+};
 use reqwest::blocking::Client;
+use serde::{de::DeserializeOwned, Serialize};
+use std::sync::RwLock;
 
 /// Blocking client for interacting with the Yandex Translate API.
 ///
 /// This client uses `reqwest::blocking` internally and is intended for
 /// synchronous / non-async use cases such as CLI tools or simple applications.
 ///
-/// Authentication is handled via [`AuthMethod`], supporting both API keys
-/// and IAM tokens.
+/// Authentication is handled via [`AuthMethod`], supporting API keys, IAM
+/// tokens, and service-account keys (with automatic IAM token minting).
 pub struct YandexTranslateClient {
     /// Underlying HTTP client
     client: Client,
@@ -17,6 +24,9 @@ pub struct YandexTranslateClient {
     auth: AuthMethod,
     /// Base URL of the Yandex Translate API
     base_url: String,
+    /// Cached IAM token, populated lazily when `auth` is a
+    /// [`AuthMethod::ServiceAccountKey`]
+    iam_cache: IamTokenCache,
 }
 
 impl YandexTranslateClient {
@@ -39,6 +49,7 @@ impl YandexTranslateClient {
             client,
             auth,
             base_url: API_BASE_URL.to_string(),
+            iam_cache: IamTokenCache::new(),
         })
     }
 
@@ -96,6 +107,89 @@ impl YandexTranslateClient {
         self
     }
 
+    /// Builds the `Authorization` header value for the configured
+    /// [`AuthMethod`], minting and caching an IAM token first if needed.
+    fn authorization_header(&self) -> Result<String> {
+        Ok(match &self.auth {
+            AuthMethod::ApiKey(key) => format!("Api-Key {key}"),
+            AuthMethod::IAMToken(token) => format!("Bearer {token}"),
+            AuthMethod::ServiceAccountKey(key) => format!("Bearer {}", self.iam_token(key)?),
+        })
+    }
+
+    /// Returns a cached IAM token for `key`, minting a fresh one if the
+    /// cache is empty or the cached token is about to expire.
+    fn iam_token(&self, key: &ServiceAccountKey) -> Result<String> {
+        if let Some(token) = self.iam_cache.valid_token() {
+            return Ok(token);
+        }
+
+        let jwt_request = iam::build_jwt_request(key)?;
+
+        let response = self
+            .client
+            .post(iam::IAM_TOKEN_URL)
+            .header("Content-Type", "application/json")
+            .json(&jwt_request)
+            .send()?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response
+                .text()
+                .unwrap_or_else(|_| "Unknown error".to_string());
+
+            return Err(Error::ApiError(format!(
+                "IAM token request returned status {}: {}",
+                status, error_text
+            )));
+        }
+
+        let token: iam::IamTokenResponse = response.json()?;
+        let expires_at = iam::parse_expires_at(&token.expires_at)?;
+        self.iam_cache.store(token.iam_token.clone(), expires_at);
+
+        Ok(token.iam_token)
+    }
+
+    /// Sends `body` as a JSON POST request to `path` under the configured
+    /// base URL, with authentication applied, and deserializes the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The HTTP request fails
+    /// - The API responds with a non-success status code
+    /// - The response body cannot be parsed
+    ///
+    /// In case of an API error, the response body is included in the error
+    /// message for easier debugging.
+    fn post<T: Serialize + ?Sized, R: DeserializeOwned>(&self, path: &str, body: &T) -> Result<R> {
+        let url = format!("{}{path}", self.base_url);
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .header("Authorization", self.authorization_header()?)
+            .json(body)
+            .send()?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response
+                .text()
+                .unwrap_or_else(|_| "Unknown error".to_string());
+
+            return Err(Error::ApiError(format!(
+                "API returned status {}: {}",
+                status, error_text
+            )));
+        }
+
+        Ok(response.json()?)
+    }
+
     /// Translates text using the Yandex Translate API.
     ///
     /// Sends a POST request to the `/translate` endpoint with the provided
@@ -118,32 +212,163 @@ impl YandexTranslateClient {
     /// In case of an API error, the response body is included in the error
     /// message for easier debugging.
     pub fn translate(&self, request: &TranslateRequest) -> Result<TranslateResponse> {
-        let url = format!("{}/translate", self.base_url);
+        self.post("/translate", request)
+    }
 
-        let mut req = self
-            .client
-            .post(&url)
-            .header("Content-Type", "application/json");
+    /// Detects the language of a piece of text.
+    ///
+    /// Sends a POST request to the `/detect` endpoint with the provided
+    /// [`DetectRequest`] and returns a [`DetectResponse`] on success.
+    ///
+    /// # Parameters
+    ///
+    /// - `request`: Language-detection request payload
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The HTTP request fails
+    /// - The API responds with a non-success status code
+    /// - The response body cannot be parsed
+    pub fn detect(&self, request: &DetectRequest) -> Result<DetectResponse> {
+        self.post("/detect", request)
+    }
 
-        req = match &self.auth {
-            AuthMethod::ApiKey(key) => req.header("Authorization", format!("Api-Key {}", key)),
-            AuthMethod::IAMToken(token) => req.header("Authorization", format!("Bearer {}", token)),
-        };
+    /// Lists the languages supported for translation.
+    ///
+    /// Sends a POST request to the `/languages` endpoint with the provided
+    /// [`ListLanguagesRequest`] and returns a [`ListLanguagesResponse`] on
+    /// success.
+    ///
+    /// # Parameters
+    ///
+    /// - `request`: Supported-languages request payload
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The HTTP request fails
+    /// - The API responds with a non-success status code
+    /// - The response body cannot be parsed
+    pub fn languages(&self, request: &ListLanguagesRequest) -> Result<ListLanguagesResponse> {
+        self.post("/languages", request)
+    }
 
-        let response = req.json(request).send()?;
+    /// Wraps this client in a [`CachingYandexTranslateClient`] that memoizes
+    /// translations so repeated texts are not re-sent to the API.
+    ///
+    /// # Parameters
+    ///
+    /// - `max_entries`: Maximum number of cached translations to keep. When
+    ///   `None`, the cache grows unbounded. When exceeded, the oldest entries
+    ///   are evicted first.
+    #[must_use]
+    pub fn with_cache(self, max_entries: Option<usize>) -> CachingYandexTranslateClient {
+        CachingYandexTranslateClient {
+            inner: self,
+            cache: RwLock::new(TranslationCache::new(max_entries)),
+        }
+    }
+}
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response
-                .text()
-                .unwrap_or_else(|_| "Unknown error".to_string());
+/// A [`YandexTranslateClient`] wrapper that memoizes translations in memory,
+/// keyed by `(target_language_code, source_language_code, text, format,
+/// speller, glossary_config)`, to avoid redundant API calls for repeated
+/// text.
+///
+/// Constructed via [`YandexTranslateClient::with_cache`].
+pub struct CachingYandexTranslateClient {
+    inner: YandexTranslateClient,
+    cache: RwLock<TranslationCache>,
+}
 
-            return Err(Error::ApiError(format!(
-                "API returned status {}: {}",
-                status, error_text
-            )));
+impl CachingYandexTranslateClient {
+    /// Translates text, serving any texts already present in the cache
+    /// without contacting the API.
+    ///
+    /// Only texts missing from the cache are sent to the API; the returned
+    /// [`TranslateResponse`] preserves the order of `request.texts`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying [`YandexTranslateClient::translate`]
+    /// call fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal cache lock is poisoned by a prior panic.
+    pub fn translate(&self, request: &TranslateRequest) -> Result<TranslateResponse> {
+        let mut translations: Vec<Option<Translation>> = Vec::with_capacity(request.texts.len());
+        let mut miss_keys = Vec::new();
+        let mut miss_texts = Vec::new();
+
+        {
+            let cache = self.cache.read().unwrap();
+            for text in request.texts {
+                let key = cache_key(request, text);
+                if let Some(translation) = cache.get(&key) {
+                    translations.push(Some(translation));
+                } else {
+                    miss_keys.push(key);
+                    miss_texts.push(*text);
+                    translations.push(None);
+                }
+            }
         }
 
-        Ok(response.json()?)
+        if !miss_texts.is_empty() {
+            let miss_request = TranslateRequest {
+                texts: &miss_texts,
+                ..request.clone()
+            };
+            let response = self.inner.translate(&miss_request)?;
+
+            let mut cache = self.cache.write().unwrap();
+            let mut miss_index = 0;
+            for slot in &mut translations {
+                if slot.is_none() {
+                    let translation = response.translations[miss_index].clone();
+                    cache.insert(miss_keys[miss_index].clone(), translation.clone());
+                    *slot = Some(translation);
+                    miss_index += 1;
+                }
+            }
+        }
+
+        Ok(TranslateResponse {
+            translations: translations.into_iter().map(Option::unwrap).collect(),
+        })
+    }
+
+    /// Removes all cached translations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal cache lock is poisoned by a prior panic.
+    pub fn clear_cache(&self) {
+        self.cache.write().unwrap().clear();
+    }
+}
+
+fn cache_key(request: &TranslateRequest, text: &str) -> CacheKey {
+    CacheKey {
+        target_language_code: request.target_language_code.to_string(),
+        source_language_code: request.source_language_code.map(ToString::to_string),
+        text: text.to_string(),
+        format: request.format,
+        speller: request.speller,
+        glossary_pairs: request.glossary_config.as_ref().map(|config| {
+            config
+                .glossary_pairs
+                .iter()
+                .map(|pair| {
+                    (
+                        pair.source_text.to_string(),
+                        pair.translated_text.to_string(),
+                        pair.exact,
+                    )
+                })
+                .collect()
+        }),
     }
 }
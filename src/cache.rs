@@ -0,0 +1,64 @@
+//! Cache-key and eviction bookkeeping shared by the blocking and async
+//! `CachingYandexTranslateClient` wrappers.
+
+use crate::{Format, Translation};
+use std::collections::{HashMap, VecDeque};
+
+/// Key a cached [`Translation`] is stored and looked up under.
+///
+/// Includes every [`crate::TranslateRequest`] field that can change the
+/// resulting translation, so that two requests differing only in (for
+/// example) `speller` or `glossary_config` never collide in the cache.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey {
+    pub(crate) target_language_code: String,
+    pub(crate) source_language_code: Option<String>,
+    pub(crate) text: String,
+    pub(crate) format: Option<Format>,
+    pub(crate) speller: Option<bool>,
+    pub(crate) glossary_pairs: Option<Vec<(String, String, bool)>>,
+}
+
+/// In-memory translation cache with optional capped, insertion-order
+/// (oldest-first) eviction.
+#[derive(Debug, Default)]
+pub(crate) struct TranslationCache {
+    entries: HashMap<CacheKey, Translation>,
+    order: VecDeque<CacheKey>,
+    max_entries: Option<usize>,
+}
+
+impl TranslationCache {
+    pub(crate) fn new(max_entries: Option<usize>) -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            max_entries,
+        }
+    }
+
+    pub(crate) fn get(&self, key: &CacheKey) -> Option<Translation> {
+        self.entries.get(key).cloned()
+    }
+
+    pub(crate) fn insert(&mut self, key: CacheKey, translation: Translation) {
+        if !self.entries.contains_key(&key) {
+            self.order.push_back(key.clone());
+        }
+        self.entries.insert(key, translation);
+
+        if let Some(max_entries) = self.max_entries {
+            while self.entries.len() > max_entries {
+                let Some(oldest) = self.order.pop_front() else {
+                    break;
+                };
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
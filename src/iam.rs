@@ -0,0 +1,174 @@
+//! JWT construction and IAM token caching for service-account authentication.
+
+use crate::{Error, Result};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::DateTime;
+use rsa::{
+    pkcs8::DecodePrivateKey,
+    pss::SigningKey,
+    signature::{RandomizedSigner, SignatureEncoding},
+    RsaPrivateKey,
+};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::{
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Endpoint used to exchange a signed JWT for an IAM token.
+pub(crate) const IAM_TOKEN_URL: &str = "https://iam.api.cloud.yandex.net/iam/v1/tokens";
+
+/// Number of seconds before expiry at which a cached IAM token is considered
+/// stale and gets re-minted.
+const REFRESH_SKEW_SECS: u64 = 60;
+
+/// A Yandex Cloud service-account authorized key.
+///
+/// This mirrors the authorized-key JSON produced by `yc iam create-key` (or
+/// downloaded from the console), so a saved key file can be parsed directly
+/// with [`ServiceAccountKey::from_json`] instead of being mapped by hand.
+/// Given such a key, the client signs and exchanges JWTs for IAM tokens on
+/// its own, so there is no need to obtain and refresh tokens manually.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServiceAccountKey {
+    /// Identifier of the authorized key.
+    pub id: String,
+
+    /// Identifier of the service account the key belongs to.
+    pub service_account_id: String,
+
+    /// PEM-encoded RSA private key.
+    pub private_key: String,
+}
+
+impl ServiceAccountKey {
+    /// Parses a service-account key from authorized-key JSON, as produced by
+    /// `yc iam create-key` or downloaded from the Yandex Cloud console.
+    ///
+    /// Unrecognized fields in `json` (such as `public_key` or `created_at`)
+    /// are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ConfigError`] if `json` is not a valid authorized key.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|err| Error::ConfigError(format!("invalid service account key JSON: {err}")))
+    }
+}
+
+#[derive(Serialize)]
+struct JwtHeader<'a> {
+    typ: &'a str,
+    alg: &'a str,
+    kid: &'a str,
+}
+
+#[derive(Serialize)]
+struct JwtClaims<'a> {
+    iss: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct IamTokenResponse {
+    #[serde(rename = "iamToken")]
+    pub iam_token: String,
+
+    /// RFC 3339 timestamp at which `iam_token` expires, as reported by the
+    /// API (Yandex IAM tokens are typically valid for around 12 hours,
+    /// regardless of the 1-hour lifetime of the JWT used to request them).
+    #[serde(rename = "expiresAt")]
+    pub expires_at: String,
+}
+
+/// Parses an `expiresAt` timestamp from an [`IamTokenResponse`] into a unix
+/// timestamp, for storage in [`IamTokenCache`].
+pub(crate) fn parse_expires_at(expires_at: &str) -> Result<u64> {
+    let parsed = DateTime::parse_from_rfc3339(expires_at).map_err(|err| {
+        Error::ApiError(format!("invalid expiresAt `{expires_at}` in IAM token response: {err}"))
+    })?;
+
+    Ok(u64::try_from(parsed.timestamp()).unwrap_or(0))
+}
+
+/// Builds a `{"jwt": <signed token>}` request body for `IAM_TOKEN_URL`.
+///
+/// The signed JWT itself is only valid for an hour, as required by the IAM
+/// API, but the IAM token it is exchanged for typically lives much longer;
+/// callers should cache against the `expiresAt` returned in the response
+/// rather than this JWT's own expiry.
+pub(crate) fn build_jwt_request(key: &ServiceAccountKey) -> Result<serde_json::Value> {
+    let iat = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is before the unix epoch")
+        .as_secs();
+    let exp = iat + 3600;
+
+    let header = JwtHeader {
+        typ: "JWT",
+        alg: "PS256",
+        kid: &key.id,
+    };
+    let claims = JwtClaims {
+        iss: &key.service_account_id,
+        aud: IAM_TOKEN_URL,
+        iat,
+        exp,
+    };
+
+    let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?);
+    let claims_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&claims)?);
+    let signing_input = format!("{header_b64}.{claims_b64}");
+
+    let private_key = RsaPrivateKey::from_pkcs8_pem(&key.private_key)
+        .map_err(|err| Error::ConfigError(format!("invalid service account private key: {err}")))?;
+    let signing_key = SigningKey::<Sha256>::new(private_key);
+    let signature = signing_key.sign_with_rng(&mut rand::rngs::OsRng, signing_input.as_bytes());
+    let signature_b64 = URL_SAFE_NO_PAD.encode(signature.to_bytes());
+
+    let jwt = format!("{signing_input}.{signature_b64}");
+
+    Ok(serde_json::json!({ "jwt": jwt }))
+}
+
+/// Caches the most recently minted IAM token for a [`ServiceAccountKey`],
+/// behind a [`Mutex`] so it can be shared across requests.
+#[derive(Debug, Default)]
+pub(crate) struct IamTokenCache {
+    cached: Mutex<Option<CachedToken>>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: String,
+    expires_at: u64,
+}
+
+impl IamTokenCache {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached token, unless it is missing or within
+    /// [`REFRESH_SKEW_SECS`] of expiring.
+    pub(crate) fn valid_token(&self) -> Option<String> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock is before the unix epoch")
+            .as_secs();
+
+        let cached = self.cached.lock().unwrap();
+        cached
+            .as_ref()
+            .filter(|cached| cached.expires_at > now + REFRESH_SKEW_SECS)
+            .map(|cached| cached.token.clone())
+    }
+
+    pub(crate) fn store(&self, token: String, expires_at: u64) {
+        *self.cached.lock().unwrap() = Some(CachedToken { token, expires_at });
+    }
+}
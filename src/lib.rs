@@ -26,6 +26,10 @@ pub enum Error {
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+mod cache;
+mod iam;
+pub use iam::ServiceAccountKey;
+
 /// Authentication method for the Yandex Translate API.
 ///
 /// This enum defines how requests to the API are authenticated.
@@ -44,6 +48,14 @@ pub enum AuthMethod {
     /// The token is sent in the `Authorization` header using the
     /// `Bearer` scheme.
     IAMToken(String),
+
+    /// Authenticate using a Yandex Cloud service-account authorized key.
+    ///
+    /// The client signs a JWT with the key's private key, exchanges it for
+    /// an IAM token, and caches that token until shortly before it expires,
+    /// re-minting it automatically. This avoids having to obtain and refresh
+    /// IAM tokens manually.
+    ServiceAccountKey(ServiceAccountKey),
 }
 
 /// Request body for a translation operation.
@@ -53,7 +65,7 @@ pub enum AuthMethod {
 ///
 /// The lifetime parameter ensures that the request can borrow data
 /// without requiring additional allocations.
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TranslateRequest<'a> {
     /// Identifier of the Yandex Cloud folder.
@@ -79,6 +91,58 @@ pub struct TranslateRequest<'a> {
     /// the source language for each input text.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_language_code: Option<&'a str>,
+
+    /// Optional format of the input texts.
+    ///
+    /// Defaults to [`Format::PlainText`] on the API side. Set this to
+    /// [`Format::Html`] to preserve HTML markup while translating.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<Format>,
+
+    /// Optional spell-correction pass applied before translating.
+    ///
+    /// When `true`, the API attempts to fix spelling mistakes in the input
+    /// texts before translating them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speller: Option<bool>,
+
+    /// Optional glossary overriding the translation of specific terms.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glossary_config: Option<GlossaryConfig<'a>>,
+}
+
+/// Format of the text being translated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Format {
+    /// Plain, unformatted text.
+    PlainText,
+
+    /// HTML markup, preserved across the translation.
+    Html,
+}
+
+/// A glossary of term translations to apply during a translation operation.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlossaryConfig<'a> {
+    /// Term translations to apply.
+    pub glossary_pairs: Vec<GlossaryPair<'a>>,
+}
+
+/// A single source-to-translation term override.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlossaryPair<'a> {
+    /// Term to match in the source text.
+    pub source_text: &'a str,
+
+    /// Translation to use in place of the matched term.
+    pub translated_text: &'a str,
+
+    /// Whether `source_text` must match exactly, rather than just a
+    /// stem/substring.
+    pub exact: bool,
 }
 
 /// Individual translation result.
@@ -108,6 +172,69 @@ pub struct TranslateResponse {
     pub translations: Vec<Translation>,
 }
 
+/// Request body for a language-detection operation.
+///
+/// This structure is serialized to JSON and sent to the `/detect`
+/// endpoint of the Yandex Translate API.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectRequest<'a> {
+    /// Identifier of the Yandex Cloud folder.
+    ///
+    /// This specifies the cloud folder where the request is billed and
+    /// authorized.
+    pub folder_id: &'a str,
+
+    /// Text whose language should be detected.
+    pub text: &'a str,
+
+    /// Optional hints narrowing down the set of candidate languages.
+    ///
+    /// Uses ISO 639-1 language codes (for example, `"en"`, `"ru"`, `"de"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code_hints: Option<&'a [&'a str]>,
+}
+
+/// Response returned by the language-detection endpoint.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectResponse {
+    /// Detected language code.
+    pub language_code: String,
+}
+
+/// Request body for listing the languages supported for translation.
+///
+/// This structure is serialized to JSON and sent to the `/languages`
+/// endpoint of the Yandex Translate API.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListLanguagesRequest<'a> {
+    /// Identifier of the Yandex Cloud folder.
+    ///
+    /// This specifies the cloud folder where the request is billed and
+    /// authorized.
+    pub folder_id: &'a str,
+}
+
+/// A single language supported by the Yandex Translate API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LanguageInfo {
+    /// ISO 639-1 language code (for example, `"en"`, `"ru"`, `"de"`).
+    pub code: String,
+
+    /// Human-readable name of the language, if provided by the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Response returned by the supported-languages endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListLanguagesResponse {
+    /// List of languages supported for translation.
+    pub languages: Vec<LanguageInfo>,
+}
+
 #[cfg(feature = "blocking")]
 pub mod blocking;
 #[cfg(feature = "blocking")]